@@ -0,0 +1,60 @@
+//! Benchmarks every polynomial-commitment scheme through the common
+//! `PolyCommitmentScheme` trait, so IPA, HyperKZG, and future schemes stay
+//! directly comparable instead of living in separate bench files.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ff::Field;
+use proving_ground::{
+    provider::{
+        mlkzg,
+        pcs::{hyperkzg, ipa_pc, PolyCommitmentScheme},
+        Bn256EngineIPA, Bn256EngineKZG, Bn256EngineMLKZG,
+    },
+    traits::Engine,
+};
+use rand_core::OsRng;
+
+fn bench_pcs_with<E: Engine, S: PolyCommitmentScheme<E>>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(name);
+
+    for &num_vars in [16, 18, 20].iter() {
+        let (pk, vk) = S::setup(num_vars).unwrap();
+        let poly = (0..(1 << num_vars))
+            .map(|_| E::Scalar::random(OsRng))
+            .collect::<Vec<_>>();
+        let point = (0..num_vars).map(|_| E::Scalar::random(OsRng)).collect::<Vec<_>>();
+        let comm = S::commit(&pk, &poly).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("commit", num_vars), &num_vars, |b, _| {
+            b.iter(|| S::commit(black_box(&pk), black_box(&poly)).unwrap())
+        });
+
+        // evaluate poly at point using the standard multilinear formula so
+        // prove/verify see a consistent (point, eval) pair
+        let eval = proving_ground::spartan::polys::multilinear::MultilinearPolynomial::new(poly.clone())
+            .evaluate(&point);
+
+        group.bench_with_input(BenchmarkId::new("prove", num_vars), &num_vars, |b, _| {
+            b.iter(|| S::prove(black_box(&pk), &comm, &poly, &point, &eval).unwrap())
+        });
+
+        let proof = S::prove(&pk, &comm, &poly, &point, &eval).unwrap();
+        group.bench_with_input(BenchmarkId::new("verify", num_vars), &num_vars, |b, _| {
+            b.iter(|| S::verify(black_box(&vk), &comm, &point, &eval, &proof).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_all_pcs(c: &mut Criterion) {
+    bench_pcs_with::<Bn256EngineIPA, ipa_pc::EvaluationEngine<Bn256EngineIPA>>(c, "ipa_pc");
+    bench_pcs_with::<Bn256EngineKZG, hyperkzg::EvaluationEngine<Bn256EngineKZG>>(c, "hyperkzg");
+    bench_pcs_with::<Bn256EngineMLKZG, mlkzg::EvaluationEngine<Bn256EngineMLKZG>>(c, "mlkzg");
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = bench_all_pcs
+}
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+//! This module defines errors returned by the library.
+use thiserror::Error;
+
+use crate::supernova::error::SuperNovaError;
+
+/// Errors returned by Nova
+#[derive(Debug, Eq, PartialEq, Error)]
+pub enum NovaError {
+    /// returned if the supplied commitment key is not of the length required
+    #[error("InvalidCommitmentKeyLength")]
+    InvalidCommitmentKeyLength,
+    /// returned if the supplied evaluation point does not match the
+    /// polynomial's number of variables
+    #[error("InvalidEvaluationPoint")]
+    InvalidEvaluationPoint,
+    /// returned if a proof failed to verify
+    #[error("ProofVerifyError: {reason}")]
+    ProofVerifyError {
+        /// the reason verification failed
+        reason: String,
+    },
+}
+
+/// A single error type for the crate, unifying [`NovaError`] (Nova's folding
+/// scheme), [`SuperNovaError`] (SuperNova's folding scheme), and the
+/// commitment-key-sizing failures a SNARK's `setup` can hit. Callers that
+/// used to match on `NovaError` or `SuperNovaError` separately can match on
+/// this instead; the `#[error(transparent)]` wrappers mean `?` still
+/// converts the underlying error with its own `Display` output, nothing is
+/// swallowed or reworded in translation.
+#[derive(Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    /// An error from Nova's folding scheme.
+    #[error(transparent)]
+    Nova(#[from] NovaError),
+    /// An error from SuperNova's folding scheme.
+    #[error(transparent)]
+    SuperNova(#[from] SuperNovaError),
+    /// A SNARK's `setup` was handed a commitment key with fewer bases than
+    /// its `ck_floor` requires. Surfacing this here, instead of letting
+    /// `prove` panic the first time it runs out of bases, gives the caller
+    /// an actionable error pointing at the exact shortfall.
+    #[error("commitment key too small: {available} bases available, {required} required")]
+    CommitmentKeyTooSmall {
+        /// the number of bases `ck_floor` requires
+        required: usize,
+        /// the number of bases the supplied commitment key actually has
+        available: usize,
+    },
+}
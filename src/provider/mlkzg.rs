@@ -0,0 +1,434 @@
+//! This module implements a multilinear KZG (MLKZG) polynomial-commitment
+//! scheme as an `EvaluationEngineTrait` for `Bn256EngineMLKZG`.
+//!
+//! Unlike `hyperkzg` (which commits to a univariate polynomial derived from
+//! the multilinear one), MLKZG commits directly to an `n`-variate
+//! multilinear polynomial as a single G1 element, under a structured
+//! reference string indexed by the boolean hypercube: `srs_levels[0][b] = [f_b(tau)]_1`
+//! where `f_b` is the multilinear Lagrange basis polynomial for `b in {0,1}^n`
+//! and `tau = (tau_1, ..., tau_n)` is the (unknown) trapdoor.
+//!
+//! Opening at `r = (r_1, ..., r_n)` uses the standard multilinear division
+//! identity
+//! ```text
+//! f(x) - f(r) = sum_i (x_i - r_i) * q_i(x)
+//! ```
+//! where `q_i` is obtained by folding the evaluation table of `f` along
+//! variable `i` (the same fold `SumcheckProof`/`MultilinearPolynomial`
+//! already use to bind a variable). The prover commits to each `q_i` and the
+//! verifier checks the pairing equation
+//! ```text
+//! e(C - [f(r)]_1, [1]_2) = sum_i e(pi_i, [tau_i - r_i]_2)
+//! ```
+//! using the G2 powers `[tau_i]_2` published in the SRS.
+use ff::Field;
+use group::Group;
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::NovaError,
+    provider::{pcs::PolyCommitmentScheme, traits::PairingGroup},
+    traits::{commitment::Len, evaluation::EvaluationEngineTrait, Engine, TranscriptEngineTrait},
+};
+
+/// The `Bn256EngineMLKZG` marker is defined alongside the other BN256
+/// engines in `provider::mod`; this module only supplies the evaluation
+/// engine it uses.
+
+/// The commitment key (structured reference string) for MLKZG.
+///
+/// `srs_levels[0]` holds the `G1` points indexed by the full `n`-variable
+/// boolean hypercube (`srs_levels[0][b] = [f_b(tau_1, ..., tau_n)]_1`) and is
+/// what `commit` uses. Opening at `r = (r_1, ..., r_n)` needs one quotient
+/// `q_i` per variable, and `q_i` is multilinear only in the *remaining*
+/// variables `x_{i+1}, ..., x_n` — committing it therefore requires an SRS
+/// built from `tau_{i+1}, ..., tau_n` alone, not a slice of the full
+/// hypercube SRS (which still carries every variable's `tau` dependence).
+/// `srs_levels[k]` (for `k = 1..=n`) is exactly that: the hypercube SRS over
+/// the trailing `n - k` taus, so `srs_levels[i + 1]` is the right key for
+/// `q_i`.
+///
+/// The levels hold `2^num_vars + 2^(num_vars - 1) + ... + 1 = 2^(num_vars + 1) - 1`
+/// `G1` elements in all, i.e. `O(2^num_vars)` — the prover's key, not the
+/// verifier's; see [`VerifierKey`] for the latter.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CommitmentKey<E: Engine>
+where
+    E::GE: PairingGroup,
+{
+    srs_levels: Vec<Vec<E::GE>>,
+    srs_g2_taus: Vec<<E::GE as PairingGroup>::G2>,
+}
+
+impl<E: Engine> Len for CommitmentKey<E>
+where
+    E::GE: PairingGroup,
+{
+    fn length(&self) -> usize {
+        self.srs_levels[0].len()
+    }
+}
+
+/// The verifier's key: just what `verify` reads off the SRS, namely the `G1`
+/// generator and the `G2` tau powers `[tau_i]_2`. `O(num_vars)` `G2`
+/// elements, unlike [`CommitmentKey`]'s `O(2^num_vars)` `G1`
+/// elements — a service persisting/reloading a verifier key (see
+/// `test_mlkzg_key_serde_roundtrip`) never has to pay for the prover's SRS.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifierKey<E: Engine>
+where
+    E::GE: PairingGroup,
+{
+    g1_generator: E::GE,
+    srs_g2_taus: Vec<<E::GE as PairingGroup>::G2>,
+}
+
+/// A commitment to an `n`-variate multilinear polynomial: a single `G1`
+/// element.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Commitment<E: Engine> {
+    comm: E::GE,
+}
+
+/// An opening proof: one quotient commitment `pi_i` per variable.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct EvaluationArgument<E: Engine>
+where
+    E::GE: PairingGroup,
+{
+    quotients: Vec<E::GE>,
+}
+
+/// Computes the hypercube SRS `[f_b(taus)]_1` for `b` ranging over the
+/// boolean hypercube of `taus.len()` variables, by the same tensor-product
+/// trick used to evaluate a multilinear poly: start from `[1]` and, for each
+/// variable, split-and-scale.
+///
+/// `taus` is consumed back-to-front so that `taus[0]` ends up controlling the
+/// *outermost* (most-significant) split of the resulting table, matching
+/// `prove`/`verify`'s folding loop below, which always eliminates the
+/// most-significant remaining variable first and pairs it with `taus[0]` of
+/// whatever tau slice is in scope. Building the tensor front-to-back instead
+/// would make `taus[0]` the least-significant bit and silently mismatch the
+/// fold order.
+fn hypercube_srs<E: Engine>(taus: &[E::Scalar]) -> Vec<E::GE> {
+    let mut evals = vec![E::Scalar::ONE];
+    for &tau_i in taus.iter().rev() {
+        let mut next = Vec::with_capacity(evals.len() * 2);
+        for e in &evals {
+            next.push(*e * (E::Scalar::ONE - tau_i));
+        }
+        for e in &evals {
+            next.push(*e * tau_i);
+        }
+        evals = next;
+    }
+
+    let gen = E::GE::generator();
+    evals.into_iter().map(|s| gen * s).collect()
+}
+
+/// Generates the SRS for an `n`-variate MLKZG instance. In production this
+/// trapdoor (`tau_1, ..., tau_n`) must come from a multi-party ceremony; here
+/// (mirroring `hyperkzg::commitment_key`) we sample it at random from `OsRng`
+/// for test/bench use, so each call yields a fresh SRS.
+pub fn setup<E: Engine>(num_vars: usize) -> CommitmentKey<E>
+where
+    E::GE: PairingGroup,
+{
+    let taus: Vec<E::Scalar> = (0..num_vars).map(|_| E::Scalar::random(OsRng)).collect();
+
+    // srs_levels[k] is the hypercube SRS built from taus[k..], so it can
+    // commit to a multilinear polynomial in the trailing `num_vars - k`
+    // variables; srs_levels[num_vars] is just the generator (the "0-variable"
+    // SRS).
+    let srs_levels: Vec<Vec<E::GE>> =
+        (0..=num_vars).map(|k| hypercube_srs::<E>(&taus[k..])).collect();
+    let srs_g2_taus = taus
+        .iter()
+        .map(|&tau_i| <E::GE as PairingGroup>::G2::generator() * tau_i)
+        .collect();
+
+    CommitmentKey { srs_levels, srs_g2_taus }
+}
+
+/// Commits to `poly` (given by its evaluations over the boolean hypercube)
+/// as `sum_b poly[b] * srs_levels[0][b]`.
+pub fn commit<E: Engine>(ck: &CommitmentKey<E>, poly: &[E::Scalar]) -> Result<Commitment<E>, NovaError>
+where
+    E::GE: PairingGroup,
+{
+    if poly.len() != ck.srs_levels[0].len() {
+        return Err(NovaError::InvalidCommitmentKeyLength);
+    }
+    let comm = E::GE::vartime_multiscalar_mul(poly, &ck.srs_levels[0]);
+    Ok(Commitment { comm })
+}
+
+/// Nova's `EvaluationEngineTrait` impl for MLKZG.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvaluationEngine<E: Engine> {
+    _p: std::marker::PhantomData<E>,
+}
+
+impl<E: Engine> EvaluationEngineTrait<E> for EvaluationEngine<E>
+where
+    E::GE: PairingGroup,
+{
+    type ProverKey = CommitmentKey<E>;
+    type VerifierKey = VerifierKey<E>;
+    type EvaluationArgument = EvaluationArgument<E>;
+
+    fn setup(ck: &CommitmentKey<E>) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError> {
+        let vk = VerifierKey {
+            g1_generator: E::GE::generator(),
+            srs_g2_taus: ck.srs_g2_taus.clone(),
+        };
+        Ok((ck.clone(), vk))
+    }
+
+    fn prove(
+        ck: &Self::ProverKey,
+        _pk: &Self::ProverKey,
+        _transcript: &mut E::TE,
+        _comm: &Commitment<E>,
+        poly: &[E::Scalar],
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+    ) -> Result<Self::EvaluationArgument, NovaError> {
+        let num_vars = point.len();
+        if poly.len() != 1 << num_vars {
+            return Err(NovaError::InvalidEvaluationPoint);
+        }
+
+        // fold the evaluation table one variable at a time (most-significant
+        // first, matching the bit order the hypercube SRS is indexed by),
+        // collecting a quotient commitment per variable. `q_i` depends only
+        // on the variables not yet folded in, so it must be committed under
+        // `ck.srs_levels[i + 1]` (the SRS over exactly those remaining
+        // taus) — NOT a slice of the previous round's SRS, which still
+        // carries the just-folded variable's tau dependence and would make
+        // the pairing check fail.
+        let mut table = poly.to_vec();
+        let mut quotients = Vec::with_capacity(num_vars);
+
+        for (i, &r_i) in point.iter().enumerate() {
+            let half = table.len() / 2;
+            let q: Vec<E::Scalar> = table[half..]
+                .iter()
+                .zip(table[..half].iter())
+                .map(|(&h, &l)| h - l)
+                .collect();
+            let q_comm = E::GE::vartime_multiscalar_mul(&q, &ck.srs_levels[i + 1]);
+            quotients.push(q_comm);
+
+            table = table[..half]
+                .iter()
+                .zip(table[half..].iter())
+                .map(|(&l, &h)| l + r_i * (h - l))
+                .collect();
+        }
+
+        debug_assert_eq!(table.len(), 1);
+        debug_assert_eq!(&table[0], eval);
+
+        Ok(EvaluationArgument { quotients })
+    }
+
+    fn verify(
+        vk: &Self::VerifierKey,
+        _transcript: &mut E::TE,
+        comm: &Commitment<E>,
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        arg: &Self::EvaluationArgument,
+    ) -> Result<(), NovaError> {
+        if arg.quotients.len() != point.len() {
+            return Err(NovaError::ProofVerifyError {
+                reason: "MLKZG: wrong number of quotient commitments".to_string(),
+            });
+        }
+
+        let lhs_g1 = comm.comm - vk.g1_generator * *eval;
+        let lhs = E::GE::pairing(&lhs_g1, &<E::GE as PairingGroup>::G2::generator());
+
+        let rhs = point
+            .iter()
+            .zip(arg.quotients.iter())
+            .zip(vk.srs_g2_taus.iter())
+            .map(|((&r_i, &pi_i), &tau_i)| {
+                let tau_minus_r = tau_i - <E::GE as PairingGroup>::G2::generator() * r_i;
+                E::GE::pairing(&pi_i, &tau_minus_r)
+            })
+            .fold(<E::GE as PairingGroup>::GT::identity(), |acc, x| acc + x);
+
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(NovaError::ProofVerifyError {
+                reason: "MLKZG: pairing check failed".to_string(),
+            })
+        }
+    }
+}
+
+impl<E: Engine> PolyCommitmentScheme<E> for EvaluationEngine<E>
+where
+    E::GE: PairingGroup,
+{
+    type ProverKey = CommitmentKey<E>;
+    type VerifierKey = VerifierKey<E>;
+    type Commitment = Commitment<E>;
+    type Proof = EvaluationArgument<E>;
+    // MLKZG's opening is a deterministic pairing check against the caller-
+    // supplied `point` — unlike hyperkzg/ipa_pc it never linearizes several
+    // claims with a sampled batching challenge, so there is nothing to derive.
+    type Challenge = ();
+
+    fn setup(max_num_vars: usize) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError> {
+        let ck = setup::<E>(max_num_vars);
+        <Self as EvaluationEngineTrait<E>>::setup(&ck)
+    }
+
+    fn commit(pk: &Self::ProverKey, poly: &[E::Scalar]) -> Result<Self::Commitment, NovaError> {
+        commit(pk, poly)
+    }
+
+    fn challenge(_comm: &Self::Commitment, _point: &[E::Scalar], _eval: &E::Scalar) -> Self::Challenge {}
+
+    fn prove_with_challenge(
+        pk: &Self::ProverKey,
+        comm: &Self::Commitment,
+        poly: &[E::Scalar],
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        _challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, NovaError> {
+        let mut transcript = E::TE::new(b"mlkzg pcs");
+        <Self as EvaluationEngineTrait<E>>::prove(pk, pk, &mut transcript, comm, poly, point, eval)
+    }
+
+    fn verify_with_challenge(
+        vk: &Self::VerifierKey,
+        comm: &Self::Commitment,
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        _challenge: &Self::Challenge,
+        proof: &Self::Proof,
+    ) -> Result<(), NovaError> {
+        let mut transcript = E::TE::new(b"mlkzg pcs");
+        <Self as EvaluationEngineTrait<E>>::verify(vk, &mut transcript, comm, point, eval, proof)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::provider::Bn256EngineMLKZG;
+
+    #[test]
+    fn test_mlkzg_commit_open_verify() {
+        let num_vars = 4;
+        let ck = setup::<Bn256EngineMLKZG>(num_vars);
+        let (pk, vk) = <EvaluationEngine<Bn256EngineMLKZG> as EvaluationEngineTrait<
+            Bn256EngineMLKZG,
+        >>::setup(&ck)
+        .unwrap();
+
+        let poly: Vec<<Bn256EngineMLKZG as Engine>::Scalar> =
+            (0..(1 << num_vars)).map(|_| <Bn256EngineMLKZG as Engine>::Scalar::random(OsRng)).collect();
+        let point: Vec<<Bn256EngineMLKZG as Engine>::Scalar> =
+            (0..num_vars).map(|_| <Bn256EngineMLKZG as Engine>::Scalar::random(OsRng)).collect();
+
+        // evaluate naively by repeated folding, mirroring MultilinearPolynomial::evaluate
+        let mut table = poly.clone();
+        for &r_i in &point {
+            let half = table.len() / 2;
+            table = table[..half]
+                .iter()
+                .zip(table[half..].iter())
+                .map(|(&l, &h)| l + r_i * (h - l))
+                .collect();
+        }
+        let eval = table[0];
+
+        let comm = commit(&pk, &poly).unwrap();
+        let mut prover_transcript = <Bn256EngineMLKZG as Engine>::TE::new(b"test_mlkzg");
+        let arg = EvaluationEngine::<Bn256EngineMLKZG>::prove(
+            &pk,
+            &pk,
+            &mut prover_transcript,
+            &comm,
+            &poly,
+            &point,
+            &eval,
+        )
+        .unwrap();
+
+        let mut verifier_transcript = <Bn256EngineMLKZG as Engine>::TE::new(b"test_mlkzg");
+        EvaluationEngine::<Bn256EngineMLKZG>::verify(
+            &vk,
+            &mut verifier_transcript,
+            &comm,
+            &point,
+            &eval,
+            &arg,
+        )
+        .unwrap();
+    }
+
+    /// A long-running service should be able to run `setup` once, persist the
+    /// resulting keys, and reload them in a later process rather than paying
+    /// for the SRS again. This serializes the prover/verifier keys, drops
+    /// them, deserializes, and checks a proof still verifies.
+    #[test]
+    fn test_mlkzg_key_serde_roundtrip() {
+        let num_vars = 4;
+        let (pk, vk) =
+            <EvaluationEngine<Bn256EngineMLKZG> as PolyCommitmentScheme<Bn256EngineMLKZG>>::setup(
+                num_vars,
+            )
+            .unwrap();
+
+        let pk_bytes = bincode::serialize(&pk).unwrap();
+        let vk_bytes = bincode::serialize(&vk).unwrap();
+        drop((pk, vk));
+        let pk: CommitmentKey<Bn256EngineMLKZG> = bincode::deserialize(&pk_bytes).unwrap();
+        let vk: VerifierKey<Bn256EngineMLKZG> = bincode::deserialize(&vk_bytes).unwrap();
+
+        let poly: Vec<<Bn256EngineMLKZG as Engine>::Scalar> =
+            (0..(1 << num_vars)).map(|_| <Bn256EngineMLKZG as Engine>::Scalar::random(OsRng)).collect();
+        let point: Vec<<Bn256EngineMLKZG as Engine>::Scalar> =
+            (0..num_vars).map(|_| <Bn256EngineMLKZG as Engine>::Scalar::random(OsRng)).collect();
+        let mut table = poly.clone();
+        for &r_i in &point {
+            let half = table.len() / 2;
+            table = table[..half]
+                .iter()
+                .zip(table[half..].iter())
+                .map(|(&l, &h)| l + r_i * (h - l))
+                .collect();
+        }
+        let eval = table[0];
+
+        let comm =
+            <EvaluationEngine<Bn256EngineMLKZG> as PolyCommitmentScheme<Bn256EngineMLKZG>>::commit(
+                &pk, &poly,
+            )
+            .unwrap();
+        let proof =
+            <EvaluationEngine<Bn256EngineMLKZG> as PolyCommitmentScheme<Bn256EngineMLKZG>>::prove(
+                &pk, &comm, &poly, &point, &eval,
+            )
+            .unwrap();
+        <EvaluationEngine<Bn256EngineMLKZG> as PolyCommitmentScheme<Bn256EngineMLKZG>>::verify(
+            &vk, &comm, &point, &eval, &proof,
+        )
+        .unwrap();
+    }
+}
@@ -4,9 +4,15 @@
 // public modules to be used as an evaluation engine with Spartan
 pub mod hyperkzg;
 pub mod ipa_pc;
+// a direct multilinear KZG PCS, as opposed to hyperkzg's univariate reduction
+pub mod mlkzg;
+// a unified `PolyCommitmentScheme` trait over the evaluation engines above,
+// so Spartan-level code can be generic over the underlying PCS
+pub mod pcs;
 
 // crate-public modules, made crate-public mostly for tests
 pub(crate) mod bn256_grumpkin;
+pub(crate) mod pasta;
 mod pedersen;
 pub(crate) mod poseidon;
 pub(crate) mod traits;
@@ -19,12 +25,14 @@ mod keccak;
 mod tests;
 
 use halo2curves::bn256::Bn256;
+use serde::{Deserialize, Serialize};
 
 use self::kzg_commitment::KZGCommitmentEngine;
 use crate::{
     provider::{
         bn256_grumpkin::{bn256, grumpkin},
         keccak::Keccak256Transcript,
+        pasta::{pallas, vesta},
         pedersen::CommitmentEngine as PedersenCommitmentEngine,
         poseidon::{PoseidonRO, PoseidonROCircuit},
     },
@@ -33,12 +41,12 @@ use crate::{
 
 /// An implementation of the Nova `Engine` trait with Grumpkin curve and
 /// Pedersen commitment scheme
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct GrumpkinEngine;
 
 /// An implementation of the Nova `Engine` trait with BN254 curve and Pedersen
 /// commitment scheme
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Bn256EngineIPA;
 
 impl Engine for Bn256EngineIPA {
@@ -63,7 +71,7 @@ impl Engine for GrumpkinEngine {
 
 /// An implementation of the Nova `Engine` trait with BN254 curve and Zeromorph
 /// commitment scheme
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Bn256EngineZM;
 
 impl Engine for Bn256EngineZM {
@@ -76,7 +84,7 @@ impl Engine for Bn256EngineZM {
     type CE = KZGCommitmentEngine<Bn256>;
 }
 /// An implementation of Nova traits with HyperKZG over the BN256 curve
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Bn256EngineKZG;
 
 impl Engine for Bn256EngineKZG {
@@ -101,6 +109,67 @@ impl CurveCycleEquipped for Bn256EngineZM {
     type Secondary = GrumpkinEngine;
 }
 
+/// An implementation of Nova traits with a direct multilinear-KZG PCS (as
+/// opposed to `Bn256EngineKZG`'s univariate HyperKZG reduction) over the
+/// BN256 curve. Requires `bn256::Point` to implement `PairingGroup` so the
+/// evaluation engine can pair commitments against the SRS's G2 powers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Bn256EngineMLKZG;
+
+impl Engine for Bn256EngineMLKZG {
+    type Base = bn256::Base;
+    type Scalar = bn256::Scalar;
+    type GE = bn256::Point;
+    type RO = PoseidonRO<Self::Base, Self::Scalar>;
+    type ROCircuit = PoseidonROCircuit<Self::Base>;
+    type TE = Keccak256Transcript<Self>;
+    type CE = KZGCommitmentEngine<Bn256>;
+}
+
+impl CurveCycleEquipped for Bn256EngineMLKZG {
+    type Secondary = GrumpkinEngine;
+}
+
+/// An implementation of the Nova `Engine` trait with the Pallas curve and
+/// Pedersen commitment scheme, completing the Pasta cycle with
+/// `VestaEngine`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PallasEngine;
+
+/// An implementation of the Nova `Engine` trait with the Vesta curve and
+/// Pedersen commitment scheme, completing the Pasta cycle with
+/// `PallasEngine`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VestaEngine;
+
+impl Engine for PallasEngine {
+    type Base = pallas::Base;
+    type Scalar = pallas::Scalar;
+    type GE = pallas::Point;
+    type RO = PoseidonRO<Self::Base, Self::Scalar>;
+    type ROCircuit = PoseidonROCircuit<Self::Base>;
+    type TE = Keccak256Transcript<Self>;
+    type CE = PedersenCommitmentEngine<Self>;
+}
+
+impl Engine for VestaEngine {
+    type Base = vesta::Base;
+    type Scalar = vesta::Scalar;
+    type GE = vesta::Point;
+    type RO = PoseidonRO<Self::Base, Self::Scalar>;
+    type ROCircuit = PoseidonROCircuit<Self::Base>;
+    type TE = Keccak256Transcript<Self>;
+    type CE = PedersenCommitmentEngine<Self>;
+}
+
+impl CurveCycleEquipped for PallasEngine {
+    type Secondary = VestaEngine;
+}
+
+impl CurveCycleEquipped for VestaEngine {
+    type Secondary = PallasEngine;
+}
+
 #[cfg(test)]
 mod test {
     use std::io::Read;
@@ -114,6 +183,7 @@ mod test {
 
     use crate::provider::{
         bn256_grumpkin::{bn256, grumpkin},
+        pasta::{pallas, vesta},
         traits::DlogGroup,
         util::msm::cpu_best_msm,
     };
@@ -167,10 +237,17 @@ mod test {
     fn test_msm() {
         test_msm_with::<bn256::Scalar, bn256::Affine>();
         test_msm_with::<grumpkin::Scalar, grumpkin::Affine>();
+        test_msm_with::<pallas::Scalar, pallas::Affine>();
+        test_msm_with::<vesta::Scalar, vesta::Affine>();
     }
 
     #[test]
     fn test_bn256_from_label() {
         impl_cycle_pair_test!(bn256);
     }
+
+    #[test]
+    fn test_pallas_from_label() {
+        impl_cycle_pair_test!(pallas);
+    }
 }
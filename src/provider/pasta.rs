@@ -0,0 +1,15 @@
+//! This module implements the Pallas/Vesta curve cycle, mirroring
+//! `bn256_grumpkin`'s thin aliasing of the underlying curve crate so the
+//! rest of the provider (Poseidon RO, Keccak transcript, Pedersen
+//! commitments) can be instantiated over it exactly as it is over BN254.
+pub mod pallas {
+    pub use pasta_curves::pallas::{Affine, Point, Scalar};
+    /// The base field of the Pallas curve, i.e. the scalar field of Vesta.
+    pub type Base = pasta_curves::pallas::Base;
+}
+
+pub mod vesta {
+    pub use pasta_curves::vesta::{Affine, Point, Scalar};
+    /// The base field of the Vesta curve, i.e. the scalar field of Pallas.
+    pub type Base = pasta_curves::vesta::Base;
+}
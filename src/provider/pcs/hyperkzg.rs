@@ -0,0 +1,168 @@
+//! [`PolyCommitmentScheme`] glue for the HyperKZG evaluation engine.
+//!
+//! This module does not reimplement HyperKZG; it adapts the existing
+//! `provider::hyperkzg::EvaluationEngine` (an `EvaluationEngineTrait` impl)
+//! to the crate-wide [`PolyCommitmentScheme`] surface so callers can be
+//! generic over the PCS.
+use std::marker::PhantomData;
+
+use crate::{
+    errors::NovaError,
+    provider::{hyperkzg::EvaluationEngine as HyperKZGEvaluationEngine, pcs::PolyCommitmentScheme},
+    traits::{
+        commitment::CommitmentEngineTrait, evaluation::EvaluationEngineTrait,
+        TranscriptEngineTrait, Engine,
+    },
+};
+
+/// The HyperKZG polynomial-commitment scheme, exposed through
+/// [`PolyCommitmentScheme`].
+#[derive(Clone, Debug)]
+pub struct EvaluationEngine<E: Engine> {
+    _p: PhantomData<E>,
+}
+
+impl<E: Engine> PolyCommitmentScheme<E> for EvaluationEngine<E>
+where
+    HyperKZGEvaluationEngine<E>: EvaluationEngineTrait<E>,
+{
+    type ProverKey = <HyperKZGEvaluationEngine<E> as EvaluationEngineTrait<E>>::ProverKey;
+    type VerifierKey = <HyperKZGEvaluationEngine<E> as EvaluationEngineTrait<E>>::VerifierKey;
+    type Commitment = <E::CE as CommitmentEngineTrait<E>>::Commitment;
+    type Proof = <HyperKZGEvaluationEngine<E> as EvaluationEngineTrait<E>>::EvaluationArgument;
+    // A scheme-tagged scalar derived from `(comm, point, eval)` by
+    // `challenge`. It is not HyperKZG's batching challenge itself: it is
+    // absorbed into the transcript, and the wrapped engine then squeezes its
+    // batching challenge from that transcript (see the note below).
+    type Challenge = E::Scalar;
+
+    fn setup(max_num_vars: usize) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError> {
+        let ck = crate::provider::hyperkzg::commitment_key(max_num_vars);
+        HyperKZGEvaluationEngine::setup(&ck)
+    }
+
+    fn commit(pk: &Self::ProverKey, poly: &[E::Scalar]) -> Result<Self::Commitment, NovaError> {
+        crate::provider::hyperkzg::commit(pk, poly)
+    }
+
+    fn challenge(comm: &Self::Commitment, point: &[E::Scalar], eval: &E::Scalar) -> Self::Challenge {
+        super::challenge_transcript::<E>(b"pcs-challenge/hyperkzg", comm, point, eval)
+    }
+
+    // NOTE: `provider::hyperkzg::{prove, verify}` still derive their own
+    // batching challenge internally rather than accepting one as an
+    // argument, so we can't hand `challenge` to them directly. Instead we
+    // absorb it into the transcript *before* calling into the wrapped
+    // engine, so the batching challenge it derives is itself a function of
+    // the externally supplied value: a proof made with one `challenge`
+    // fails `verify_with_challenge` under a different one, because the two
+    // transcripts (and everything squeezed from them) diverge. That's a
+    // real binding, not just a comparison against a second call to
+    // `challenge`.
+    fn prove_with_challenge(
+        pk: &Self::ProverKey,
+        comm: &Self::Commitment,
+        poly: &[E::Scalar],
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, NovaError> {
+        let mut transcript = E::TE::new(b"pcs");
+        transcript.absorb(b"decider-challenge", challenge);
+        HyperKZGEvaluationEngine::prove(pk.ck(), pk, &mut transcript, comm, poly, point, eval)
+    }
+
+    fn verify_with_challenge(
+        vk: &Self::VerifierKey,
+        comm: &Self::Commitment,
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        challenge: &Self::Challenge,
+        proof: &Self::Proof,
+    ) -> Result<(), NovaError> {
+        let mut transcript = E::TE::new(b"pcs");
+        transcript.absorb(b"decider-challenge", challenge);
+        HyperKZGEvaluationEngine::verify(vk, &mut transcript, comm, point, eval, proof)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::Field;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::provider::Bn256EngineKZG;
+
+    /// Builds a random poly/point/eval triple (the caller commits to it),
+    /// evaluating naively by repeated folding (mirroring `MultilinearPolynomial::evaluate`).
+    fn random_instance(
+        num_vars: usize,
+    ) -> (
+        Vec<<Bn256EngineKZG as Engine>::Scalar>,
+        Vec<<Bn256EngineKZG as Engine>::Scalar>,
+        <Bn256EngineKZG as Engine>::Scalar,
+    ) {
+        let poly: Vec<<Bn256EngineKZG as Engine>::Scalar> = (0..(1 << num_vars))
+            .map(|_| <Bn256EngineKZG as Engine>::Scalar::random(OsRng))
+            .collect();
+        let point: Vec<<Bn256EngineKZG as Engine>::Scalar> =
+            (0..num_vars).map(|_| <Bn256EngineKZG as Engine>::Scalar::random(OsRng)).collect();
+
+        let mut table = poly.clone();
+        for &r_i in &point {
+            let half = table.len() / 2;
+            table = table[..half]
+                .iter()
+                .zip(table[half..].iter())
+                .map(|(&l, &h)| l + r_i * (h - l))
+                .collect();
+        }
+        let eval = table[0];
+
+        (poly, point, eval)
+    }
+
+    #[test]
+    fn test_hyperkzg_challenge_round_trip() {
+        let num_vars = 4;
+        let (pk, vk) = EvaluationEngine::<Bn256EngineKZG>::setup(num_vars).unwrap();
+        let (poly, point, eval) = random_instance(num_vars);
+        let comm = EvaluationEngine::<Bn256EngineKZG>::commit(&pk, &poly).unwrap();
+
+        let challenge = EvaluationEngine::<Bn256EngineKZG>::challenge(&comm, &point, &eval);
+        let proof = EvaluationEngine::<Bn256EngineKZG>::prove_with_challenge(
+            &pk, &comm, &poly, &point, &eval, &challenge,
+        )
+        .unwrap();
+        EvaluationEngine::<Bn256EngineKZG>::verify_with_challenge(
+            &vk, &comm, &point, &eval, &challenge, &proof,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_hyperkzg_verify_with_challenge_rejects_wrong_challenge() {
+        let num_vars = 4;
+        let (pk, vk) = EvaluationEngine::<Bn256EngineKZG>::setup(num_vars).unwrap();
+        let (poly, point, eval) = random_instance(num_vars);
+        let comm = EvaluationEngine::<Bn256EngineKZG>::commit(&pk, &poly).unwrap();
+
+        let challenge = EvaluationEngine::<Bn256EngineKZG>::challenge(&comm, &point, &eval);
+        let proof = EvaluationEngine::<Bn256EngineKZG>::prove_with_challenge(
+            &pk, &comm, &poly, &point, &eval, &challenge,
+        )
+        .unwrap();
+
+        let wrong_challenge = challenge + <Bn256EngineKZG as Engine>::Scalar::ONE;
+        assert!(EvaluationEngine::<Bn256EngineKZG>::verify_with_challenge(
+            &vk,
+            &comm,
+            &point,
+            &eval,
+            &wrong_challenge,
+            &proof,
+        )
+        .is_err());
+    }
+}
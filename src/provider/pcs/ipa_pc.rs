@@ -0,0 +1,164 @@
+//! [`PolyCommitmentScheme`] glue for the inner-product-argument (IPA) PCS.
+//!
+//! Mirrors `pcs::hyperkzg`: adapts the existing
+//! `provider::ipa_pc::EvaluationEngine` to the crate-wide
+//! [`PolyCommitmentScheme`] surface instead of reimplementing IPA.
+use std::marker::PhantomData;
+
+use crate::{
+    errors::NovaError,
+    provider::{ipa_pc::EvaluationEngine as IPAEvaluationEngine, pcs::PolyCommitmentScheme},
+    traits::{
+        commitment::CommitmentEngineTrait, evaluation::EvaluationEngineTrait,
+        TranscriptEngineTrait, Engine,
+    },
+};
+
+/// The IPA polynomial-commitment scheme, exposed through
+/// [`PolyCommitmentScheme`].
+#[derive(Clone, Debug)]
+pub struct EvaluationEngine<E: Engine> {
+    _p: PhantomData<E>,
+}
+
+impl<E: Engine> PolyCommitmentScheme<E> for EvaluationEngine<E>
+where
+    IPAEvaluationEngine<E>: EvaluationEngineTrait<E>,
+{
+    type ProverKey = <IPAEvaluationEngine<E> as EvaluationEngineTrait<E>>::ProverKey;
+    type VerifierKey = <IPAEvaluationEngine<E> as EvaluationEngineTrait<E>>::VerifierKey;
+    type Commitment = <E::CE as CommitmentEngineTrait<E>>::Commitment;
+    type Proof = <IPAEvaluationEngine<E> as EvaluationEngineTrait<E>>::EvaluationArgument;
+    // IPA folds its opening claim round-by-round with a fresh challenge per
+    // round; `Challenge` here is a seed absorbed into the transcript before
+    // those rounds, so the round challenges the wrapped engine squeezes all
+    // depend on it, as with `hyperkzg`'s `Challenge`.
+    type Challenge = E::Scalar;
+
+    fn setup(max_num_vars: usize) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError> {
+        let ck = E::CE::setup(b"ipa_pc pcs setup", 1 << max_num_vars);
+        IPAEvaluationEngine::setup(&ck)
+    }
+
+    fn commit(pk: &Self::ProverKey, poly: &[E::Scalar]) -> Result<Self::Commitment, NovaError> {
+        crate::provider::ipa_pc::commit(pk, poly)
+    }
+
+    fn challenge(comm: &Self::Commitment, point: &[E::Scalar], eval: &E::Scalar) -> Self::Challenge {
+        super::challenge_transcript::<E>(b"pcs-challenge/ipa_pc", comm, point, eval)
+    }
+
+    // See the matching note in `pcs::hyperkzg`: `provider::ipa_pc` doesn't
+    // yet accept an externally supplied challenge, so we absorb it into the
+    // transcript before handing that transcript to the wrapped engine. The
+    // round challenges IPA folds with are then themselves a function of
+    // `challenge`, so a proof produced under one `challenge` fails
+    // `verify_with_challenge` under another — a real binding rather than a
+    // self-comparison.
+    fn prove_with_challenge(
+        pk: &Self::ProverKey,
+        comm: &Self::Commitment,
+        poly: &[E::Scalar],
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, NovaError> {
+        let mut transcript = E::TE::new(b"pcs");
+        transcript.absorb(b"decider-challenge", challenge);
+        IPAEvaluationEngine::prove(pk.ck(), pk, &mut transcript, comm, poly, point, eval)
+    }
+
+    fn verify_with_challenge(
+        vk: &Self::VerifierKey,
+        comm: &Self::Commitment,
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        challenge: &Self::Challenge,
+        proof: &Self::Proof,
+    ) -> Result<(), NovaError> {
+        let mut transcript = E::TE::new(b"pcs");
+        transcript.absorb(b"decider-challenge", challenge);
+        IPAEvaluationEngine::verify(vk, &mut transcript, comm, point, eval, proof)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ff::Field;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::provider::Bn256EngineIPA;
+
+    /// Builds a random poly/point/eval triple (the caller commits to it),
+    /// evaluating naively by repeated folding (mirroring `MultilinearPolynomial::evaluate`).
+    fn random_instance(
+        num_vars: usize,
+    ) -> (
+        Vec<<Bn256EngineIPA as Engine>::Scalar>,
+        Vec<<Bn256EngineIPA as Engine>::Scalar>,
+        <Bn256EngineIPA as Engine>::Scalar,
+    ) {
+        let poly: Vec<<Bn256EngineIPA as Engine>::Scalar> = (0..(1 << num_vars))
+            .map(|_| <Bn256EngineIPA as Engine>::Scalar::random(OsRng))
+            .collect();
+        let point: Vec<<Bn256EngineIPA as Engine>::Scalar> =
+            (0..num_vars).map(|_| <Bn256EngineIPA as Engine>::Scalar::random(OsRng)).collect();
+
+        let mut table = poly.clone();
+        for &r_i in &point {
+            let half = table.len() / 2;
+            table = table[..half]
+                .iter()
+                .zip(table[half..].iter())
+                .map(|(&l, &h)| l + r_i * (h - l))
+                .collect();
+        }
+        let eval = table[0];
+
+        (poly, point, eval)
+    }
+
+    #[test]
+    fn test_ipa_pc_challenge_round_trip() {
+        let num_vars = 4;
+        let (pk, vk) = EvaluationEngine::<Bn256EngineIPA>::setup(num_vars).unwrap();
+        let (poly, point, eval) = random_instance(num_vars);
+        let comm = EvaluationEngine::<Bn256EngineIPA>::commit(&pk, &poly).unwrap();
+
+        let challenge = EvaluationEngine::<Bn256EngineIPA>::challenge(&comm, &point, &eval);
+        let proof = EvaluationEngine::<Bn256EngineIPA>::prove_with_challenge(
+            &pk, &comm, &poly, &point, &eval, &challenge,
+        )
+        .unwrap();
+        EvaluationEngine::<Bn256EngineIPA>::verify_with_challenge(
+            &vk, &comm, &point, &eval, &challenge, &proof,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_ipa_pc_verify_with_challenge_rejects_wrong_challenge() {
+        let num_vars = 4;
+        let (pk, vk) = EvaluationEngine::<Bn256EngineIPA>::setup(num_vars).unwrap();
+        let (poly, point, eval) = random_instance(num_vars);
+        let comm = EvaluationEngine::<Bn256EngineIPA>::commit(&pk, &poly).unwrap();
+
+        let challenge = EvaluationEngine::<Bn256EngineIPA>::challenge(&comm, &point, &eval);
+        let proof = EvaluationEngine::<Bn256EngineIPA>::prove_with_challenge(
+            &pk, &comm, &poly, &point, &eval, &challenge,
+        )
+        .unwrap();
+
+        let wrong_challenge = challenge + <Bn256EngineIPA as Engine>::Scalar::ONE;
+        assert!(EvaluationEngine::<Bn256EngineIPA>::verify_with_challenge(
+            &vk,
+            &comm,
+            &point,
+            &eval,
+            &wrong_challenge,
+            &proof,
+        )
+        .is_err());
+    }
+}
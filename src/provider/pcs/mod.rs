@@ -0,0 +1,145 @@
+//! A unified surface over this crate's polynomial-commitment schemes (PCS).
+//!
+//! `hyperkzg` and `ipa_pc` each grew their own setup/commit/open/verify glue
+//! over time, which meant every `RelaxedR1CSSNARKTrait` impl that wanted to
+//! be generic over the underlying PCS had to hand-roll that genericity
+//! itself. This module re-exports both schemes and gives them a single
+//! [`PolyCommitmentScheme`] trait so Spartan-level code (and benchmarks) can
+//! be written once against the trait and instantiated with whichever scheme
+//! fits, with Zeromorph and future schemes (see `mlkzg`) following the same
+//! shape.
+pub mod hyperkzg;
+pub mod ipa_pc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::NovaError,
+    traits::{commitment::CommitmentEngineTrait, Engine, TranscriptEngineTrait},
+};
+
+/// Derives a scheme-tagged challenge from `(comm, point, eval)` for the
+/// `hyperkzg`/`ipa_pc` adapters, whose wrapped `EvaluationEngineTrait` impls
+/// don't yet accept an externally supplied challenge (see the note on
+/// `prove_with_challenge` in each module). `domain` must be unique per
+/// scheme so two structurally different adapters never derive the same
+/// value from the same `(comm, point, eval)`.
+pub(super) fn challenge_transcript<E: Engine>(
+    domain: &'static [u8],
+    comm: &<E::CE as CommitmentEngineTrait<E>>::Commitment,
+    point: &[E::Scalar],
+    eval: &E::Scalar,
+) -> E::Scalar
+where
+    E::CE: CommitmentEngineTrait<E>,
+{
+    let mut transcript = E::TE::new(domain);
+    transcript.absorb(b"C", comm);
+    transcript.absorb(b"point", &point.to_vec());
+    transcript.absorb(b"eval", eval);
+    transcript.squeeze(b"c").unwrap()
+}
+
+/// A polynomial-commitment scheme usable as an evaluation engine for Spartan.
+///
+/// This is deliberately a thin umbrella over the existing
+/// `EvaluationEngineTrait` impls: it does not replace them, it lets callers
+/// that only care about setup/commit/prove/verify depend on one trait
+/// instead of naming a concrete scheme.
+pub trait PolyCommitmentScheme<E: Engine>: Clone + Send + Sync {
+    /// Prover key, containing whatever part of the SRS/commitment key the
+    /// prover needs to commit to and open a polynomial. `Serialize`/
+    /// `Deserialize` so an expensive `setup` can be cached to disk and
+    /// reloaded rather than regenerated per process.
+    type ProverKey: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+    /// Verifier key, containing whatever part of the SRS/commitment key the
+    /// verifier needs to check an opening.
+    type VerifierKey: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+    /// A commitment to a multilinear polynomial.
+    type Commitment: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+    /// An opening proof attesting to an evaluation of a committed polynomial.
+    type Proof: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+    /// The Fiat–Shamir challenge(s) this scheme's opening protocol derives
+    /// from `(comm, point, eval)` (e.g. a batching/evaluation challenge that
+    /// linearizes several claims into one group-element check).
+    type Challenge: Clone + Send + Sync;
+
+    /// Generates prover and verifier keys sized to commit to polynomials with
+    /// up to `max_num_vars` variables.
+    fn setup(max_num_vars: usize) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError>;
+
+    /// Commits to a multilinear polynomial given in evaluation form.
+    fn commit(pk: &Self::ProverKey, poly: &[E::Scalar]) -> Result<Self::Commitment, NovaError>;
+
+    /// Derives the challenge(s) `prove`/`verify` would otherwise squeeze from
+    /// the transcript internally, from the commitment, point, and claimed
+    /// value alone. Exposing this separately lets a circuit that embeds a
+    /// KZG/IPA opening check (e.g. a Groth16 "decider") recompute the same
+    /// challenge in-circuit and feed it to an off-circuit
+    /// `prove_with_challenge`/`verify_with_challenge` call, so the in-circuit
+    /// and native hashing agree. Implementations must fix and document the
+    /// byte-encoding they use for `comm`/`point`/`eval` here.
+    fn challenge(comm: &Self::Commitment, point: &[E::Scalar], eval: &E::Scalar) -> Self::Challenge;
+
+    /// Produces a proof that `poly` evaluates to `eval` at `point`, binding
+    /// the proof to a challenge already derived via [`Self::challenge`].
+    /// Implementations that cannot hand the challenge straight to their
+    /// wrapped evaluation engine's own transcript must still make the result
+    /// a true function of `challenge` (e.g. by absorbing it into the
+    /// transcript they seed that engine with), so that
+    /// `verify_with_challenge` rejects a proof produced under a different
+    /// challenge.
+    fn prove_with_challenge(
+        pk: &Self::ProverKey,
+        comm: &Self::Commitment,
+        poly: &[E::Scalar],
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, NovaError>;
+
+    /// Verifies that `comm` opens to `eval` at `point`, given a challenge
+    /// already derived via [`Self::challenge`].
+    fn verify_with_challenge(
+        vk: &Self::VerifierKey,
+        comm: &Self::Commitment,
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        challenge: &Self::Challenge,
+        proof: &Self::Proof,
+    ) -> Result<(), NovaError>;
+
+    /// Produces a proof that `poly` evaluates to `eval` at `point`. A thin
+    /// wrapper that derives the challenge via [`Self::challenge`] and then
+    /// calls [`Self::prove_with_challenge`]; kept for callers that don't need
+    /// to reuse the challenge outside the PCS (i.e. everyone but an
+    /// in-circuit decider).
+    fn prove(
+        pk: &Self::ProverKey,
+        comm: &Self::Commitment,
+        poly: &[E::Scalar],
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+    ) -> Result<Self::Proof, NovaError> {
+        let challenge = Self::challenge(comm, point, eval);
+        Self::prove_with_challenge(pk, comm, poly, point, eval, &challenge)
+    }
+
+    /// Verifies that `comm` opens to `eval` at `point`. A thin wrapper that
+    /// derives the challenge via [`Self::challenge`] and then calls
+    /// [`Self::verify_with_challenge`].
+    fn verify(
+        vk: &Self::VerifierKey,
+        comm: &Self::Commitment,
+        point: &[E::Scalar],
+        eval: &E::Scalar,
+        proof: &Self::Proof,
+    ) -> Result<(), NovaError> {
+        let challenge = Self::challenge(comm, point, eval);
+        Self::verify_with_challenge(vk, comm, point, eval, &challenge, proof)
+    }
+}
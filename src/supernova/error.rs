@@ -5,14 +5,17 @@ use thiserror::Error;
 
 use crate::errors::NovaError;
 
-// TODO: These are in a dumb spot imo, they should be defined at the crate root
-// and cover everything. Also, we should use `transparent`
+// These live in their own module, separate from `NovaError`;
+// `crate::errors::Error` wraps both this and
+// `NovaError` transparently, so code that wants one error type spanning
+// Nova, SuperNova, and SNARK setup can use that instead of matching on
+// `SuperNovaError` and `NovaError` separately.
 
 /// Errors returned by Nova
 #[derive(Debug, Eq, PartialEq, Error)]
 pub enum SuperNovaError {
     /// Nova error
-    #[error("NovaError")]
+    #[error(transparent)]
     NovaError(#[from] NovaError),
     /// missing commitment key
     #[error("MissingCK")]
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::NovaError,
+    errors::{Error, NovaError},
     r1cs::{R1CSShape, RelaxedR1CSInstance, RelaxedR1CSWitness},
     traits::Engine,
     CommitmentKey,
@@ -34,10 +34,17 @@ pub trait RelaxedR1CSSNARKTrait<E: Engine>:
     Send + Sync + Serialize + for<'de> Deserialize<'de>
 {
     /// A type that represents the prover's key
-    type ProverKey: Send + Sync;
+    ///
+    /// `ProverKey`/`VerifierKey` are `Serialize`/`Deserialize` so a caller
+    /// that already paid for an expensive `setup` can persist the result
+    /// (e.g. to disk) and reload it in a later process instead of
+    /// regenerating it. Implementors with curve-generic fields should mark
+    /// them `#[serde(bound = "")]` so the generic `E` doesn't leak a
+    /// spurious `Serialize`/`Deserialize` bound onto callers.
+    type ProverKey: Send + Sync + Serialize + for<'de> Deserialize<'de>;
 
     /// A type that represents the verifier's key
-    type VerifierKey: Send + Sync + Serialize;
+    type VerifierKey: Send + Sync + Serialize + for<'de> Deserialize<'de>;
 
     /// This associated function (not a method) provides a hint that offers
     /// a minimum sizing cue for the commitment key used by this SNARK
@@ -50,10 +57,14 @@ pub trait RelaxedR1CSSNARKTrait<E: Engine>:
     }
 
     /// Produces the keys for the prover and the verifier
+    ///
+    /// Returns `Error::CommitmentKeyTooSmall` rather than panicking if `ck`
+    /// has fewer bases than `Self::ck_floor()` requires for `S` — callers
+    /// that hit this should regenerate `ck` with at least that many bases.
     fn setup(
         ck: Arc<CommitmentKey<E>>,
         S: &R1CSShape<E>,
-    ) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError>;
+    ) -> Result<(Self::ProverKey, Self::VerifierKey), Error>;
 
     /// Produces a new SNARK for a relaxed R1CS
     fn prove(
@@ -74,10 +85,10 @@ pub trait BatchedRelaxedR1CSSNARKTrait<E: Engine>:
     Send + Sync + Serialize + for<'de> Deserialize<'de>
 {
     /// A type that represents the prover's key
-    type ProverKey: Send + Sync;
+    type ProverKey: Send + Sync + Serialize + for<'de> Deserialize<'de>;
 
     /// A type that represents the verifier's key
-    type VerifierKey: Send + Sync + DigestHelperTrait<E>;
+    type VerifierKey: Send + Sync + Serialize + for<'de> Deserialize<'de> + DigestHelperTrait<E>;
 
     // NOTES: If we don't need something more general here, this is just an odd
     // thing to have defined generically since it just calls the weird function
@@ -96,11 +107,14 @@ pub trait BatchedRelaxedR1CSSNARKTrait<E: Engine>:
     /// **Note:** This method should be cheap and should not copy most of the
     /// commitment key. Look at `CommitmentEngineTrait::setup` for generating
     /// SRS data.
+    ///
+    /// Returns `Error::CommitmentKeyTooSmall` rather than panicking if `ck`
+    /// has fewer bases than `Self::ck_floor()` requires for `S`.
     fn setup(
         ck: Arc<CommitmentKey<E>>, // NOTES: Why `Arc` this?
         S: Vec<&R1CSShape<E>>,     /* NOTES: Why not a &[R1CSShape] here?, would get the same
                                     * thing across as an iter i think */
-    ) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError>;
+    ) -> Result<(Self::ProverKey, Self::VerifierKey), Error>;
 
     /// Produces a new SNARK for a batch of relaxed R1CS
     fn prove(
@@ -121,3 +135,72 @@ pub trait DigestHelperTrait<E: Engine> {
     /// Returns the digest of the verifier's key
     fn digest(&self) -> E::Scalar;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        provider::Bn256EngineIPA,
+        traits::commitment::{CommitmentEngineTrait, Len},
+    };
+
+    /// A minimal `RelaxedR1CSSNARKTrait` impl whose `setup` does nothing but
+    /// the ck-floor check, with a deliberately nontrivial floor (8 bases,
+    /// ignoring the shape) so there's something for the check to reject.
+    /// Exists so the test below exercises a real `setup` entry point instead
+    /// of reimplementing the check it's supposed to cover.
+    #[derive(Serialize, Deserialize)]
+    struct FixtureSnark;
+
+    impl<E: Engine> RelaxedR1CSSNARKTrait<E> for FixtureSnark {
+        type ProverKey = ();
+        type VerifierKey = ();
+
+        fn ck_floor() -> Box<dyn for<'a> Fn(&'a R1CSShape<E>) -> usize> {
+            Box::new(|_shape: &R1CSShape<E>| 8)
+        }
+
+        fn setup(
+            ck: Arc<CommitmentKey<E>>,
+            S: &R1CSShape<E>,
+        ) -> Result<(Self::ProverKey, Self::VerifierKey), Error> {
+            let required = Self::ck_floor()(S);
+            let available = ck.length();
+            if available < required {
+                return Err(Error::CommitmentKeyTooSmall { required, available });
+            }
+            Ok(((), ()))
+        }
+
+        fn prove(
+            _ck: &CommitmentKey<E>,
+            _pk: &Self::ProverKey,
+            _S: &R1CSShape<E>,
+            _U: &RelaxedR1CSInstance<E>,
+            _W: &RelaxedR1CSWitness<E>,
+        ) -> Result<Self, NovaError> {
+            unimplemented!("FixtureSnark only exercises setup's ck-floor check")
+        }
+
+        fn verify(
+            &self,
+            _vk: &Self::VerifierKey,
+            _U: &RelaxedR1CSInstance<E>,
+        ) -> Result<(), NovaError> {
+            unimplemented!("FixtureSnark only exercises setup's ck-floor check")
+        }
+    }
+
+    #[test]
+    fn setup_rejects_a_commitment_key_under_its_ck_floor() {
+        type E = Bn256EngineIPA;
+
+        // an empty shape: FixtureSnark's ck_floor ignores it and always
+        // demands 8 bases, so its contents don't matter to this test
+        let shape = R1CSShape::<E>::new(0, 0, 0, &[], &[], &[]).unwrap();
+        let ck = Arc::new(<E as Engine>::CE::setup(b"fixture-snark-ck-floor-test", 4));
+
+        let err = FixtureSnark::setup(ck, &shape).unwrap_err();
+        assert_eq!(err, Error::CommitmentKeyTooSmall { required: 8, available: 4 });
+    }
+}